@@ -12,11 +12,19 @@
 //! JS (swarm-extension) -> N-API -> workmux library -> tmux/wezterm/kitty
 //! ```
 
+use std::io::{BufRead, BufReader};
+use std::os::fd::{FromRawFd, OwnedFd};
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
 use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi_derive::napi;
 use workmux::{
-	create_backend, detect_backend, persist_agent_update, AgentStatus, BackendType,
-	CreateWindowParams, StateStore,
+	create_backend, detect_backend, persist_agent_restart, persist_agent_update, AgentStatus,
+	BackendType, CreateWindowParams, PtySize, SessionInfo, SplitDirection, SplitPaneParams,
+	StateStore,
 };
 
 use crate::task;
@@ -109,6 +117,24 @@ pub fn workmux_detect_environment() -> task::Async<WorkmuxEnvironment> {
 	})
 }
 
+/// Terminal geometry in character cells.
+#[napi(object)]
+pub struct WorkmuxPtySize {
+	/// Number of rows.
+	pub rows: u16,
+	/// Number of columns.
+	pub cols: u16,
+}
+
+impl From<WorkmuxPtySize> for PtySize {
+	fn from(size: WorkmuxPtySize) -> Self {
+		PtySize {
+			rows: size.rows,
+			cols: size.cols,
+		}
+	}
+}
+
 /// Parameters for creating a new multiplexer window.
 #[napi(object)]
 pub struct WorkmuxCreateWindowParams {
@@ -120,12 +146,16 @@ pub struct WorkmuxCreateWindowParams {
 	pub cwd: String,
 	/// Optional window ID to insert after (for ordering).
 	pub after_window: Option<String>,
+	/// Command to run in the window (defaults to a bare shell).
+	pub command: Option<String>,
+	/// Requested terminal geometry for deterministic capture and wrapping.
+	pub size: Option<WorkmuxPtySize>,
 }
 
 /// Create a new multiplexer window/tab.
 ///
 /// # Parameters
-/// - `params`: Window creation parameters (prefix, name, cwd, optional after_window)
+/// - `params`: Window creation parameters (prefix, name, cwd, optional after_window, optional command, optional size)
 ///
 /// # Returns
 /// The pane ID of the newly created window.
@@ -148,6 +178,8 @@ pub fn workmux_create_window(params: WorkmuxCreateWindowParams) -> task::Async<S
 			name: &params.name,
 			cwd: &cwd,
 			after_window: params.after_window.as_deref(),
+			command: params.command.as_deref(),
+			size: params.size.map(Into::into),
 		};
 
 		mux.create_window(create_params)
@@ -155,6 +187,73 @@ pub fn workmux_create_window(params: WorkmuxCreateWindowParams) -> task::Async<S
 	})
 }
 
+/// Direction in which to split a pane.
+#[napi(string_enum)]
+pub enum WorkmuxSplitDirection {
+	/// Place the new pane to the side (a vertical divider).
+	Horizontal,
+	/// Place the new pane below (a horizontal divider).
+	Vertical,
+}
+
+impl From<WorkmuxSplitDirection> for SplitDirection {
+	fn from(dir: WorkmuxSplitDirection) -> Self {
+		match dir {
+			WorkmuxSplitDirection::Horizontal => SplitDirection::Horizontal,
+			WorkmuxSplitDirection::Vertical => SplitDirection::Vertical,
+		}
+	}
+}
+
+/// Parameters for splitting an existing pane.
+#[napi(object)]
+pub struct WorkmuxSplitPaneParams {
+	/// Pane to split.
+	pub pane_id: String,
+	/// Direction of the split.
+	pub direction: WorkmuxSplitDirection,
+	/// Size of the new pane as a percentage of the original (1-100).
+	pub size_percent: Option<u8>,
+	/// Working directory for the new pane (defaults to the source pane's cwd).
+	pub cwd: Option<String>,
+	/// Command to run in the new pane (defaults to a bare shell).
+	pub command: Option<String>,
+}
+
+/// Split an existing pane, returning the new pane's id.
+///
+/// # Parameters
+/// - `params`: Split parameters (pane_id, direction, optional size_percent, cwd, command)
+///
+/// # Returns
+/// The pane ID of the newly created pane.
+///
+/// # Errors
+/// Returns an error if the multiplexer isn't running or the split fails.
+#[napi(js_name = "workmuxSplitPane")]
+pub fn workmux_split_pane(params: WorkmuxSplitPaneParams) -> task::Async<String> {
+	task::blocking("workmux.split_pane", (), move |_| {
+		let backend_type = detect_backend();
+		let mux = create_backend(backend_type);
+
+		if !mux.is_running().unwrap_or(false) {
+			return Err(Error::from_reason("Multiplexer is not running"));
+		}
+
+		let cwd = params.cwd.as_ref().map(std::path::PathBuf::from);
+		let split_params = SplitPaneParams {
+			pane_id: &params.pane_id,
+			direction: params.direction.into(),
+			size_percent: params.size_percent,
+			cwd: cwd.as_deref(),
+			command: params.command.as_deref(),
+		};
+
+		mux.split_pane(split_params)
+			.map_err(|e| Error::from_reason(format!("Failed to split pane: {e}")))
+	})
+}
+
 /// Check if workmux multiplexer is available and running.
 ///
 /// # Returns
@@ -254,6 +353,8 @@ pub struct WorkmuxAgentInfo {
 	pub title: Option<String>,
 	/// Unix timestamp of last status change.
 	pub status_ts: Option<f64>,
+	/// Number of times a supervisor has restarted this agent's command.
+	pub restart_count: u32,
 }
 
 /// List all tracked agents from the workmux state store.
@@ -278,11 +379,122 @@ pub fn workmux_list_agents() -> task::Async<Vec<WorkmuxAgentInfo>> {
 				status: a.status.map(Into::into),
 				title: a.pane_title,
 				status_ts: a.status_ts.map(|ts| ts as f64),
+				restart_count: a.restart_count,
+			})
+			.collect())
+	})
+}
+
+/// Information about a multiplexer session.
+#[napi(object)]
+pub struct WorkmuxSessionInfo {
+	/// Session name.
+	pub name: String,
+	/// Whether a client is currently attached to the session.
+	pub attached: bool,
+	/// Number of windows in the session.
+	pub window_count: u32,
+	/// Whether this is the most-recently-used ("previous") session.
+	pub is_previous: bool,
+}
+
+/// List all multiplexer sessions.
+///
+/// The session recorded as last-focused in the [`StateStore`] is flagged with
+/// `is_previous`, letting a coordinator show where a bare
+/// [`workmux_switch_session`] would return to.
+///
+/// # Returns
+/// Array of session info objects.
+///
+/// # Errors
+/// Returns an error if the multiplexer isn't running or listing fails.
+#[napi(js_name = "workmuxListSessions")]
+pub fn workmux_list_sessions() -> task::Async<Vec<WorkmuxSessionInfo>> {
+	task::blocking("workmux.list_sessions", (), move |_| {
+		let backend_type = detect_backend();
+		let mux = create_backend(backend_type);
+
+		if !mux.is_running().unwrap_or(false) {
+			return Err(Error::from_reason("Multiplexer is not running"));
+		}
+
+		let sessions: Vec<SessionInfo> = mux
+			.list_sessions()
+			.map_err(|e| Error::from_reason(format!("Failed to list sessions: {e}")))?;
+
+		let previous = StateStore::new()
+			.ok()
+			.and_then(|store| store.previous_session().ok().flatten());
+
+		Ok(sessions
+			.into_iter()
+			.map(|s| WorkmuxSessionInfo {
+				is_previous: previous.as_deref() == Some(s.name.as_str()),
+				name: s.name,
+				attached: s.attached,
+				window_count: s.window_count as u32,
 			})
 			.collect())
 	})
 }
 
+/// Switch focus to a multiplexer session.
+///
+/// # Parameters
+/// - `name`: Session to focus. When omitted, returns to the previous
+///   (last-focused) session tracked in the [`StateStore`].
+/// - `detach_others`: Detach other clients from the target session (defaults to
+///   `false` when omitted).
+///
+/// The session being left is recorded as the new "previous" session so a
+/// subsequent bare call hops back.
+///
+/// # Errors
+/// Returns an error if the target session doesn't exist or no previous session
+/// is recorded when called with no argument.
+#[napi(js_name = "workmuxSwitchSession")]
+pub fn workmux_switch_session(
+	name: Option<String>,
+	detach_others: Option<bool>,
+) -> task::Async<()> {
+	task::blocking("workmux.switch_session", (), move |_| {
+		let backend_type = detect_backend();
+		let mux = create_backend(backend_type);
+
+		if !mux.is_running().unwrap_or(false) {
+			return Err(Error::from_reason("Multiplexer is not running"));
+		}
+
+		let store = StateStore::new()
+			.map_err(|e| Error::from_reason(format!("Failed to open state store: {e}")))?;
+
+		let target = match name {
+			Some(name) => name,
+			None => store
+				.previous_session()
+				.map_err(|e| Error::from_reason(format!("Failed to read previous session: {e}")))?
+				.ok_or_else(|| Error::from_reason("No previous session recorded"))?,
+		};
+
+		// Record the session we are leaving so a bare call can hop back.
+		let leaving = mux.current_session();
+
+		mux.switch_session(&target, detach_others.unwrap_or(false))
+			.map_err(|e| Error::from_reason(format!("Failed to switch session: {e}")))?;
+
+		// Best-effort: the focus change is the primary effect, so a state-store
+		// failure here should not fail a switch that already took place.
+		if let Some(leaving) = leaving {
+			if leaving != target {
+				let _ = store.set_previous_session(&leaving);
+			}
+		}
+
+		Ok(())
+	})
+}
+
 /// Send keys (command) to a specific pane.
 ///
 /// # Parameters
@@ -331,6 +543,573 @@ pub fn workmux_capture_pane(pane_id: String, lines: Option<u32>) -> task::Async<
 	})
 }
 
+/// Un-escape a tmux control-mode `%output` payload back to raw bytes.
+///
+/// Control mode emits every byte outside the printable ASCII range — and the
+/// backslash itself (`\134`) — as a backslash followed by three octal digits
+/// (e.g. `\015` for CR); all other bytes are printable and pass through
+/// verbatim. A backslash with fewer than three octal digits following it is
+/// treated literally, matching tmux's own encoder.
+fn unescape_control_output(payload: &str) -> Vec<u8> {
+	let bytes = payload.as_bytes();
+	let mut out = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+	while i < bytes.len() {
+		if bytes[i] == b'\\' && i + 4 <= bytes.len() {
+			let octal = &bytes[i + 1..i + 4];
+			if octal.iter().all(|b| (b'0'..=b'7').contains(b)) {
+				let value = octal
+					.iter()
+					.fold(0u16, |acc, b| acc * 8 + u16::from(b - b'0'));
+				out.push(value as u8);
+				i += 4;
+				continue;
+			}
+		}
+		out.push(bytes[i]);
+		i += 1;
+	}
+	out
+}
+
+/// Handle returned by [`workmux_subscribe_pane_output`] for detaching the
+/// control-mode client and ending the output stream.
+#[napi]
+pub struct WorkmuxPaneSubscription {
+	stop: Arc<AtomicBool>,
+	child: Arc<Mutex<Option<ControlClient>>>,
+}
+
+#[napi]
+impl WorkmuxPaneSubscription {
+	/// Detach the control-mode client and stop delivering output to the callback.
+	///
+	/// Safe to call more than once; subsequent calls are no-ops.
+	#[napi]
+	pub fn unsubscribe(&self) {
+		self.stop.store(true, Ordering::SeqCst);
+		if let Ok(mut guard) = self.child.lock() {
+			if let Some(mut client) = guard.take() {
+				client.shutdown();
+			}
+		}
+	}
+}
+
+impl Drop for WorkmuxPaneSubscription {
+	/// Detach the control client if the JS handle is dropped without an
+	/// explicit `unsubscribe`, so the `tmux -C attach` process and reader
+	/// thread cannot outlive the subscription.
+	fn drop(&mut self) {
+		self.unsubscribe();
+	}
+}
+
+/// A spawned `tmux -C` control-mode client together with the pty master that
+/// keeps its controlling terminal open.
+///
+/// tmux refuses to speak control mode unless its stdin is a real terminal:
+/// attaching with a null stdin exits immediately (`tcgetattr failed`) and emits
+/// no notifications. We therefore allocate a pty, hand the slave to the child as
+/// stdin, and retain the master for the client's lifetime — dropping `_master`
+/// closes the terminal and lets the client exit cleanly.
+struct ControlClient {
+	child: Child,
+	/// Pty master fd; held open so the control client keeps streaming.
+	_master: OwnedFd,
+}
+
+impl ControlClient {
+	/// Kill the control client and reap it; the pty master closes when the
+	/// client is dropped.
+	fn shutdown(&mut self) {
+		let _ = self.child.kill();
+		let _ = self.child.wait();
+	}
+}
+
+/// Resolve the tmux session that owns `pane_id`.
+///
+/// A control client attaches to the server's most-recently-used session unless
+/// scoped with `-t`, so it would only see notifications for panes in that one
+/// session; callers pass the result to [`spawn_control_client`] to bind the
+/// client to the pane's own session. Returns `None` if the pane can't be found.
+fn session_for_pane(pane_id: &str) -> Option<String> {
+	tmux_pane_format(pane_id, "#{session_name}")
+}
+
+/// Resolve the tmux window id (e.g. `@3`) that contains `pane_id`.
+fn window_for_pane(pane_id: &str) -> Option<String> {
+	tmux_pane_format(pane_id, "#{window_id}")
+}
+
+/// Query a single `display-message` format string for `pane_id`.
+fn tmux_pane_format(pane_id: &str, format: &str) -> Option<String> {
+	let out = Command::new("tmux")
+		.args(["display-message", "-p", "-t", pane_id, "-F", format])
+		.stderr(Stdio::null())
+		.output()
+		.ok()?;
+	if !out.status.success() {
+		return None;
+	}
+	let value = String::from_utf8_lossy(&out.stdout).trim().to_string();
+	(!value.is_empty()).then_some(value)
+}
+
+/// Spawn a read-only `tmux -C` control-mode client on a fresh pty, scoped to
+/// `session`, returning the client handle and its piped stdout for
+/// line-oriented parsing.
+///
+/// `-r` attaches read-only so the control client neither participates in session
+/// sizing nor resizes the pane a user is viewing; `-t <session>` binds it to the
+/// session that owns the pane of interest rather than the server's default.
+fn spawn_control_client(session: &str) -> std::io::Result<(ControlClient, ChildStdout)> {
+	let mut master: libc::c_int = 0;
+	let mut slave: libc::c_int = 0;
+	// SAFETY: all out-pointers are valid; the optional termios/winsize args are
+	// null, asking for the system defaults.
+	let rc = unsafe {
+		libc::openpty(
+			&mut master,
+			&mut slave,
+			std::ptr::null_mut(),
+			std::ptr::null(),
+			std::ptr::null(),
+		)
+	};
+	if rc != 0 {
+		return Err(std::io::Error::last_os_error());
+	}
+	// SAFETY: `openpty` succeeded, so both fds are freshly owned by this process.
+	let master = unsafe { OwnedFd::from_raw_fd(master) };
+	let slave = unsafe { OwnedFd::from_raw_fd(slave) };
+
+	let mut child = Command::new("tmux")
+		.args(["-C", "attach", "-r", "-t", session])
+		.stdin(Stdio::from(slave))
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.spawn()?;
+	let stdout = child.stdout.take().ok_or_else(|| {
+		std::io::Error::new(
+			std::io::ErrorKind::BrokenPipe,
+			"Control-mode client produced no stdout",
+		)
+	})?;
+	Ok((ControlClient { child, _master: master }, stdout))
+}
+
+/// Stream live output from a pane via a tmux control-mode client.
+///
+/// Attaches a control-mode client (`tmux -C attach`) and parses its
+/// line-oriented notification protocol: each guarded message begins with `%`,
+/// and `%output %<pane-id> <octal-escaped-bytes>` lines whose pane id matches
+/// `pane_id` are un-escaped to raw bytes and pushed to `callback` as they
+/// arrive. `%begin`/`%end`/`%error` pairs wrap command replies and are
+/// consumed without being forwarded. This replaces the poll-based
+/// [`workmux_capture_pane`] with a push stream.
+///
+/// # Returns
+/// A [`WorkmuxPaneSubscription`] whose `unsubscribe` method detaches the client.
+///
+/// # Errors
+/// Returns an error if the backend is not tmux or the control-mode client
+/// cannot be spawned.
+#[napi(js_name = "workmuxSubscribePaneOutput")]
+pub fn workmux_subscribe_pane_output(
+	pane_id: String,
+	callback: ThreadsafeFunction<Buffer>,
+) -> Result<WorkmuxPaneSubscription> {
+	if detect_backend() != BackendType::Tmux {
+		return Err(Error::from_reason(
+			"Live output subscription is only supported on the tmux backend",
+		));
+	}
+
+	let session = session_for_pane(&pane_id)
+		.ok_or_else(|| Error::from_reason(format!("Pane {pane_id} not found")))?;
+	let (client, stdout) = spawn_control_client(&session)
+		.map_err(|e| Error::from_reason(format!("Failed to attach control-mode client: {e}")))?;
+
+	let stop = Arc::new(AtomicBool::new(false));
+	let child = Arc::new(Mutex::new(Some(client)));
+
+	let thread_stop = Arc::clone(&stop);
+	std::thread::spawn(move || {
+		let reader = BufReader::new(stdout);
+		// True while inside a `%begin`..`%end`/`%error` command-reply block.
+		let mut in_reply = false;
+		for line in reader.lines() {
+			if thread_stop.load(Ordering::SeqCst) {
+				break;
+			}
+			let line = match line {
+				Ok(line) => line,
+				Err(_) => break,
+			};
+			let Some(rest) = line.strip_prefix('%') else {
+				// Reply bodies are raw, unguarded lines; ignore them.
+				continue;
+			};
+			let (verb, args) = match rest.split_once(' ') {
+				Some((verb, args)) => (verb, args),
+				None => (rest, ""),
+			};
+			match verb {
+				"begin" => in_reply = true,
+				"end" | "error" => in_reply = false,
+				// Notifications never interleave inside a reply block, but guard
+				// anyway so a reply line that happens to start with `%` is ignored.
+				_ if in_reply => {}
+				"output" => {
+					// `%<pane-id> <octal-escaped-bytes>`.
+					let Some((target, payload)) = args.split_once(' ') else {
+						continue;
+					};
+					if target.strip_prefix('%').unwrap_or(target) != pane_id.trim_start_matches('%')
+					{
+						continue;
+					}
+					let bytes = unescape_control_output(payload);
+					callback.call(Ok(bytes.into()), ThreadsafeFunctionCallMode::NonBlocking);
+				}
+				// %window-add, %window-close, %layout-change, %exit, etc. are not
+				// forwarded to output subscribers.
+				_ => {}
+			}
+		}
+	});
+
+	Ok(WorkmuxPaneSubscription { stop, child })
+}
+
+/// Options for [`workmux_search_pane`].
+#[napi(object)]
+pub struct WorkmuxSearchOptions {
+	/// Treat `pattern` as a regular expression rather than a literal substring.
+	pub regex: Option<bool>,
+	/// Number of scrollback lines to capture (default 50).
+	pub lines: Option<u32>,
+	/// Maximum number of matches to return (default: all).
+	pub max_matches: Option<u32>,
+}
+
+/// A single match found in a pane's captured output.
+#[napi(object)]
+pub struct WorkmuxPaneMatch {
+	/// The full line the match occurred on.
+	pub line: String,
+	/// Offset of the line from the bottom of the capture (0 = last line).
+	pub line_offset: u32,
+	/// Column (character index) where the match starts.
+	pub start_col: u32,
+	/// Column (character index) one past where the match ends.
+	pub end_col: u32,
+}
+
+/// Search a pane's captured scrollback for a pattern, returning match positions.
+///
+/// Captures `lines` of scrollback and scans it for `pattern` (a literal
+/// substring, or a regular expression when `regex` is set), returning one
+/// [`WorkmuxPaneMatch`] per non-overlapping match with its line, offset from
+/// the bottom, and start/end columns. This lets a caller locate a prompt,
+/// error signature, or completion marker and target that region without
+/// shipping the whole buffer across the N-API boundary.
+///
+/// # Returns
+/// Matches ordered top-to-bottom; empty if nothing matched or capture failed.
+///
+/// # Errors
+/// Returns an error if `regex` is set and `pattern` is not a valid expression.
+#[napi(js_name = "workmuxSearchPane")]
+pub fn workmux_search_pane(
+	pane_id: String,
+	pattern: String,
+	options: Option<WorkmuxSearchOptions>,
+) -> task::Async<Vec<WorkmuxPaneMatch>> {
+	task::blocking("workmux.search_pane", (), move |_| {
+		let options = options.unwrap_or(WorkmuxSearchOptions {
+			regex: None,
+			lines: None,
+			max_matches: None,
+		});
+
+		let max_matches = options.max_matches.map(|m| m as usize);
+		let regex = if options.regex.unwrap_or(false) {
+			Some(
+				regex::Regex::new(&pattern)
+					.map_err(|e| Error::from_reason(format!("Invalid search pattern: {e}")))?,
+			)
+		} else {
+			None
+		};
+		if max_matches == Some(0) {
+			return Ok(Vec::new());
+		}
+
+		let backend_type = detect_backend();
+		let mux = create_backend(backend_type);
+
+		if !mux.is_running().unwrap_or(false) {
+			return Ok(Vec::new());
+		}
+
+		let line_count = options.lines.unwrap_or(50).min(u16::MAX as u32) as u16;
+		let Some(content) = mux.capture_pane(&pane_id, line_count) else {
+			return Ok(Vec::new());
+		};
+
+		// `line_offset` counts from the bottom: the last captured line is 0.
+		// Scan bottom-to-top so that `max_matches` keeps the most recent hits.
+		let lines: Vec<&str> = content.lines().collect();
+		let total = lines.len();
+		let mut matches = Vec::new();
+		'outer: for (idx, line) in lines.iter().enumerate().rev() {
+			let line_offset = (total - 1 - idx) as u32;
+			for (start, end) in line_match_ranges(line, &pattern, regex.as_ref()) {
+				matches.push(WorkmuxPaneMatch {
+					start_col: line[..start].chars().count() as u32,
+					end_col: line[..end].chars().count() as u32,
+					line: (*line).to_string(),
+					line_offset,
+				});
+				if max_matches.is_some_and(|max| matches.len() >= max) {
+					break 'outer;
+				}
+			}
+		}
+
+		// Present matches top-to-bottom (stable, so per-line column order holds).
+		matches.sort_by(|a, b| b.line_offset.cmp(&a.line_offset));
+		Ok(matches)
+	})
+}
+
+/// Byte ranges of every non-overlapping match of `pattern` within `line`.
+///
+/// Uses `regex` when supplied, otherwise a literal substring scan.
+fn line_match_ranges(
+	line: &str,
+	pattern: &str,
+	regex: Option<&regex::Regex>,
+) -> Vec<(usize, usize)> {
+	if let Some(re) = regex {
+		return re.find_iter(line).map(|m| (m.start(), m.end())).collect();
+	}
+	if pattern.is_empty() {
+		return Vec::new();
+	}
+	let mut ranges = Vec::new();
+	let mut from = 0;
+	while let Some(rel) = line[from..].find(pattern) {
+		let start = from + rel;
+		let end = start + pattern.len();
+		ranges.push((start, end));
+		from = end;
+	}
+	ranges
+}
+
+/// Policy applied when a supervised agent's command exits.
+#[napi(string_enum)]
+pub enum WorkmuxOnExitPolicy {
+	/// Re-run `command` in the same pane (up to `max_restarts`).
+	Restart,
+	/// Leave the dead pane open for inspection.
+	KeepOpen,
+	/// Tear down the window containing the pane.
+	KillWindow,
+}
+
+/// Parameters for supervising an agent pane.
+#[napi(object)]
+pub struct WorkmuxSuperviseParams {
+	/// Action to take when the pane's command exits.
+	pub on_exit: WorkmuxOnExitPolicy,
+	/// Command to re-run under the `Restart` policy. Required for `Restart`.
+	pub command: Option<String>,
+	/// Delay before re-running the command, in milliseconds (default 1000).
+	pub restart_delay_ms: Option<u32>,
+	/// Maximum number of restarts before giving up (default 5).
+	pub max_restarts: Option<u32>,
+}
+
+/// Handle returned by [`workmux_supervise_agent`] for ending supervision.
+#[napi]
+pub struct WorkmuxSupervisor {
+	stop: Arc<AtomicBool>,
+	child: Arc<Mutex<Option<ControlClient>>>,
+}
+
+#[napi]
+impl WorkmuxSupervisor {
+	/// Stop supervising the pane, detaching any control-mode watcher.
+	///
+	/// Safe to call more than once; subsequent calls are no-ops.
+	#[napi]
+	pub fn stop(&self) {
+		self.stop.store(true, Ordering::SeqCst);
+		if let Ok(mut guard) = self.child.lock() {
+			if let Some(mut client) = guard.take() {
+				client.shutdown();
+			}
+		}
+	}
+}
+
+impl Drop for WorkmuxSupervisor {
+	fn drop(&mut self) {
+		self.stop();
+	}
+}
+
+/// Block until the supervised pane's command exits, returning `true` on exit
+/// or `false` if `stop` was raised first. Under tmux it also returns `false`
+/// when the pane's session or window cannot be resolved, the control client
+/// cannot be spawned, or the notification stream ends.
+///
+/// Under tmux this scopes a control client to the pane's session and watches for
+/// the pane's window closing (`%window-close`) or the pane dropping out of a
+/// `%layout-change` — tmux has no per-pane exit verb — confirming the latter
+/// against the backend before reporting an exit. Other backends fall back to
+/// polling pane liveness. The spawned control client, if any, is stored in
+/// `child_slot` so the supervisor handle can detach it.
+fn wait_for_pane_exit(
+	pane_id: &str,
+	stop: &Arc<AtomicBool>,
+	child_slot: &Arc<Mutex<Option<ControlClient>>>,
+) -> bool {
+	if detect_backend() == BackendType::Tmux {
+		let (Some(session), Some(window_id)) =
+			(session_for_pane(pane_id), window_for_pane(pane_id))
+		else {
+			return false;
+		};
+		let Ok((client, stdout)) = spawn_control_client(&session) else {
+			return false;
+		};
+		if let Ok(mut guard) = child_slot.lock() {
+			// Reap any client from a previous watch cycle before storing this one.
+			if let Some(mut old) = guard.replace(client) {
+				old.shutdown();
+			}
+		}
+		let mux = create_backend(BackendType::Tmux);
+		let reader = BufReader::new(stdout);
+		for line in reader.lines() {
+			if stop.load(Ordering::SeqCst) {
+				return false;
+			}
+			let Ok(line) = line else {
+				return false;
+			};
+			let Some(rest) = line.strip_prefix('%') else {
+				continue;
+			};
+			let (verb, args) = rest.split_once(' ').unwrap_or((rest, ""));
+			let target_window = args.split_whitespace().next().unwrap_or("");
+			match verb {
+				// The pane's window closed — its last command exited with it.
+				"window-close" if target_window == window_id => return true,
+				// A pane left the window; confirm it was ours before acting.
+				"layout-change"
+					if target_window == window_id
+						&& !mux.pane_alive(pane_id).unwrap_or(true) =>
+				{
+					return true;
+				}
+				_ => {}
+			}
+		}
+		false
+	} else {
+		let backend_type = detect_backend();
+		let mux = create_backend(backend_type);
+		while !stop.load(Ordering::SeqCst) {
+			if !mux.pane_alive(pane_id).unwrap_or(false) {
+				return true;
+			}
+			std::thread::sleep(std::time::Duration::from_millis(500));
+		}
+		false
+	}
+}
+
+/// Supervise an agent pane, applying an on-exit policy when its command ends.
+///
+/// Watches for the pane's command exiting (via tmux control-mode
+/// `%window-close`/`%layout-change` notifications, or a poll fallback),
+/// then applies `on_exit`: re-run `command` in the same pane after
+/// `restart_delay_ms` up to `max_restarts`, leave the dead pane open, or kill
+/// the containing window. Restart counts are persisted to the [`StateStore`]
+/// and surfaced through [`WorkmuxAgentInfo::restart_count`].
+///
+/// # Returns
+/// A [`WorkmuxSupervisor`] whose `stop` method ends supervision.
+///
+/// # Errors
+/// Returns an error if the multiplexer isn't running, or if the `Restart`
+/// policy is requested without a `command`.
+#[napi(js_name = "workmuxSuperviseAgent")]
+pub fn workmux_supervise_agent(
+	pane_id: String,
+	params: WorkmuxSuperviseParams,
+) -> Result<WorkmuxSupervisor> {
+	let backend_type = detect_backend();
+	let mux = create_backend(backend_type);
+	if !mux.is_running().unwrap_or(false) {
+		return Err(Error::from_reason("Multiplexer is not running"));
+	}
+	if matches!(params.on_exit, WorkmuxOnExitPolicy::Restart) && params.command.is_none() {
+		return Err(Error::from_reason(
+			"The Restart policy requires a command to re-run",
+		));
+	}
+
+	let restart_delay = u64::from(params.restart_delay_ms.unwrap_or(1000));
+	let max_restarts = params.max_restarts.unwrap_or(5);
+
+	let stop = Arc::new(AtomicBool::new(false));
+	let child = Arc::new(Mutex::new(None));
+
+	let thread_stop = Arc::clone(&stop);
+	let thread_child = Arc::clone(&child);
+	std::thread::spawn(move || {
+		let mux = create_backend(detect_backend());
+		let mut restarts = 0u32;
+		loop {
+			if !wait_for_pane_exit(&pane_id, &thread_stop, &thread_child) {
+				break; // stopped, or the watch ended, before the pane exited
+			}
+			match params.on_exit {
+				WorkmuxOnExitPolicy::KeepOpen => break,
+				WorkmuxOnExitPolicy::KillWindow => {
+					let _ = mux.kill_window_for_pane(&pane_id);
+					break;
+				}
+				WorkmuxOnExitPolicy::Restart => {
+					if restarts >= max_restarts {
+						break;
+					}
+					std::thread::sleep(std::time::Duration::from_millis(restart_delay));
+					if thread_stop.load(Ordering::SeqCst) {
+						break;
+					}
+					let command = params.command.as_deref().unwrap_or_default();
+					if mux.respawn_pane(&pane_id, command).is_err() {
+						break;
+					}
+					restarts += 1;
+					persist_agent_restart(mux.as_ref(), &pane_id, restarts);
+				}
+			}
+		}
+	});
+
+	Ok(WorkmuxSupervisor { stop, child })
+}
+
 /// Check if a window with the given name exists.
 ///
 /// # Parameters